@@ -0,0 +1,35 @@
+extends Control
+## Scrolling credits roll. Emits `finished` when the text has scrolled
+## past or the player skips with accept/cancel.
+
+signal finished
+
+const SCROLL_SPEED := 40.0
+
+var _done := false
+
+@onready var text: Label = $CreditsText
+
+
+func _ready() -> void:
+	text.position.y = size.y
+
+
+func _process(delta: float) -> void:
+	text.position.y -= SCROLL_SPEED * delta
+	if text.position.y < -text.size.y:
+		_finish()
+
+
+func _unhandled_input(event: InputEvent) -> void:
+	if event.is_action_pressed("ui_accept") or event.is_action_pressed("ui_cancel"):
+		_finish()
+
+
+func _finish() -> void:
+	if _done:
+		return
+	_done = true
+	set_process(false)
+	set_process_unhandled_input(false)
+	finished.emit()
@@ -0,0 +1,9 @@
+extends Node
+## Global signal bus. Systems publish here instead of poking each other
+## directly; subscribers (stats, presence, kernel log) connect at startup.
+
+signal player_damaged(amount: int, source: StringName)
+signal stage_changed(stage: StringName)
+signal command_executed(command: String)
+signal music_toggled(playing: bool)
+signal npc_talked_to(npc: StringName)
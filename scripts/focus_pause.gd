@@ -0,0 +1,39 @@
+extends Node
+## Pauses the simulation and silences music while the window is unfocused.
+
+## Set to true to let music keep playing in the background.
+const KEEP_MUSIC := false
+
+var _paused_by_us := false
+var _muted_by_us := false
+
+
+func _notification(what: int) -> void:
+	match what:
+		NOTIFICATION_APPLICATION_FOCUS_OUT:
+			_focus_lost()
+		NOTIFICATION_APPLICATION_FOCUS_IN:
+			_focus_regained()
+
+
+func _focus_lost() -> void:
+	if not get_tree().paused:
+		get_tree().paused = true
+		_paused_by_us = true
+	var music := AudioServer.get_bus_index("Music")
+	if music < 0:
+		return
+	if not KEEP_MUSIC and not AudioServer.is_bus_mute(music):
+		AudioServer.set_bus_mute(music, true)
+		_muted_by_us = true
+
+
+func _focus_regained() -> void:
+	if _paused_by_us:
+		get_tree().paused = false
+		_paused_by_us = false
+	if _muted_by_us:
+		var music := AudioServer.get_bus_index("Music")
+		if music >= 0:
+			AudioServer.set_bus_mute(music, false)
+		_muted_by_us = false
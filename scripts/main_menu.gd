@@ -0,0 +1,37 @@
+extends Control
+## Title menu shown before the boot sequence. The FSM decides what each
+## selection leads to; this scene only reports the player's choice.
+
+signal continue_requested
+signal new_game_requested
+signal options_requested
+
+@onready var continue_button: Button = %ContinueButton
+
+
+func _ready() -> void:
+	continue_button.disabled = not _can_continue()
+	if continue_button.disabled:
+		%NewGameButton.grab_focus()
+	else:
+		continue_button.grab_focus()
+
+
+func _can_continue() -> bool:
+	return SaveManager.any_save_exists() or SaveManager.latest_autosave() != ""
+
+
+func _on_continue_pressed() -> void:
+	continue_requested.emit()
+
+
+func _on_new_game_pressed() -> void:
+	new_game_requested.emit()
+
+
+func _on_options_pressed() -> void:
+	options_requested.emit()
+
+
+func _on_quit_pressed() -> void:
+	get_tree().quit()
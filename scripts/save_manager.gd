@@ -0,0 +1,81 @@
+extends Node
+## Slot-based save store. Current progress lives in `data` (stage,
+## position, HP, flags, inventory, stats, unlocks as systems are ported)
+## and is serialized as JSON under user://saves/.
+
+const SLOT_COUNT := 3
+const AUTOSAVE_COUNT := 2
+const SAVE_DIR := "user://saves"
+
+var data: Dictionary = {}
+
+var _next_autosave := 0
+
+
+func _ready() -> void:
+	EventBus.stage_changed.connect(func(_stage: StringName) -> void: autosave())
+
+
+func slot_path(slot: int) -> String:
+	return "%s/slot_%d.json" % [SAVE_DIR, slot]
+
+
+func autosave_path(index: int) -> String:
+	return "%s/auto_%d.json" % [SAVE_DIR, index]
+
+
+## Writes to a rotating autosave slot. Milestones beyond stage
+## transitions (combat victories, entering stage 3) call this directly.
+func autosave() -> void:
+	_write(autosave_path(_next_autosave))
+	_next_autosave = (_next_autosave + 1) % AUTOSAVE_COUNT
+
+
+func latest_autosave() -> String:
+	var best := ""
+	var best_time := 0
+	for index in AUTOSAVE_COUNT:
+		var path := autosave_path(index)
+		if FileAccess.file_exists(path):
+			var time := FileAccess.get_modified_time(path)
+			if time >= best_time:
+				best = path
+				best_time = time
+	return best
+
+
+func has_save(slot: int) -> bool:
+	return FileAccess.file_exists(slot_path(slot))
+
+
+func any_save_exists() -> bool:
+	for slot in SLOT_COUNT:
+		if has_save(slot):
+			return true
+	return false
+
+
+func save_slot(slot: int) -> bool:
+	return _write(slot_path(slot))
+
+
+func _write(path: String) -> bool:
+	DirAccess.make_dir_recursive_absolute(SAVE_DIR)
+	var file := FileAccess.open(path, FileAccess.WRITE)
+	if file == null:
+		push_error("could not write %s: %s" % [path, error_string(FileAccess.get_open_error())])
+		return false
+	file.store_string(JSON.stringify(data, "\t"))
+	return true
+
+
+func load_slot(slot: int) -> bool:
+	var file := FileAccess.open(slot_path(slot), FileAccess.READ)
+	if file == null:
+		return false
+	var parsed: Variant = JSON.parse_string(file.get_as_text())
+	if parsed is not Dictionary:
+		push_error("save slot %d is corrupt, ignoring it" % slot)
+		return false
+	data = parsed
+	return true
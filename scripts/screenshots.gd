@@ -0,0 +1,21 @@
+extends Node
+## F12 writes a timestamped PNG of the current frame to user://screenshots/.
+
+const SCREENSHOT_DIR := "user://screenshots"
+
+
+func _unhandled_input(event: InputEvent) -> void:
+	if event is InputEventKey and event.pressed and not event.echo and event.keycode == KEY_F12:
+		capture()
+
+
+func capture() -> void:
+	DirAccess.make_dir_recursive_absolute(SCREENSHOT_DIR)
+	var image := get_viewport().get_texture().get_image()
+	var stamp := Time.get_datetime_string_from_system().replace(":", "-")
+	var path := "%s/%s.png" % [SCREENSHOT_DIR, stamp]
+	var err := image.save_png(path)
+	if err != OK:
+		push_error("could not save screenshot: %s" % error_string(err))
+		return
+	print("screenshot saved to ", ProjectSettings.globalize_path(path))